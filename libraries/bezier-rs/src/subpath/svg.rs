@@ -0,0 +1,495 @@
+use super::*;
+use super::transform::cubic_arc_handle_length;
+use glam::DVec2;
+use std::f64::consts::{PI, TAU};
+use std::iter::Peekable;
+use std::str::Chars;
+
+/// A cursor over SVG path `d` attribute text, tokenizing commands, numbers, and the compact single-digit arc flags.
+struct PathTokenizer<'a> {
+	chars: Peekable<Chars<'a>>,
+}
+
+impl<'a> PathTokenizer<'a> {
+	fn new(d: &'a str) -> Self {
+		Self { chars: d.chars().peekable() }
+	}
+
+	fn skip_separators(&mut self) {
+		while matches!(self.chars.peek(), Some(c) if c.is_whitespace() || *c == ',') {
+			self.chars.next();
+		}
+	}
+
+	fn next_command(&mut self) -> Option<char> {
+		self.skip_separators();
+		self.chars.next_if(|c| c.is_ascii_alphabetic())
+	}
+
+	/// Reads a single floating point number, accepting an optional sign, digits, a decimal point, and an exponent.
+	fn next_number(&mut self) -> Option<f64> {
+		self.skip_separators();
+
+		let mut token = String::new();
+		if matches!(self.chars.peek(), Some('+') | Some('-')) {
+			token.push(self.chars.next().unwrap());
+		}
+		let mut saw_digit = false;
+		while matches!(self.chars.peek(), Some(c) if c.is_ascii_digit()) {
+			token.push(self.chars.next().unwrap());
+			saw_digit = true;
+		}
+		if self.chars.peek() == Some(&'.') {
+			token.push(self.chars.next().unwrap());
+			while matches!(self.chars.peek(), Some(c) if c.is_ascii_digit()) {
+				token.push(self.chars.next().unwrap());
+				saw_digit = true;
+			}
+		}
+		if !saw_digit {
+			return None;
+		}
+		if matches!(self.chars.peek(), Some('e') | Some('E')) {
+			token.push(self.chars.next().unwrap());
+			if matches!(self.chars.peek(), Some('+') | Some('-')) {
+				token.push(self.chars.next().unwrap());
+			}
+			while matches!(self.chars.peek(), Some(c) if c.is_ascii_digit()) {
+				token.push(self.chars.next().unwrap());
+			}
+		}
+
+		token.parse().ok()
+	}
+
+	fn next_point(&mut self) -> Option<DVec2> {
+		Some(DVec2::new(self.next_number()?, self.next_number()?))
+	}
+
+	/// Reads an elliptical arc's large-arc and sweep flags, which the SVG grammar allows to be packed with no
+	/// separator (e.g. `a25,25 0 1050,50` means flags `1` and `0`), so each is read as exactly one digit.
+	fn next_flag(&mut self) -> Option<bool> {
+		self.skip_separators();
+		match self.chars.next()? {
+			'0' => Some(false),
+			'1' => Some(true),
+			_ => None,
+		}
+	}
+}
+
+/// Decomposes an elliptical arc (in the SVG endpoint parameterization) into cubic Bezier segments, each spanning at
+/// most 90 degrees, via the standard endpoint-to-center conversion. Returns `None` when the arc degenerates to a
+/// straight line (coincident endpoints, or a zero radius), in which case the caller should emit a line instead.
+fn arc_to_cubics(start: DVec2, rx: f64, ry: f64, x_axis_rotation_degrees: f64, large_arc: bool, sweep: bool, end: DVec2) -> Option<Vec<(DVec2, DVec2, DVec2)>> {
+	let (mut rx, mut ry) = (rx.abs(), ry.abs());
+	if rx == 0. || ry == 0. || start == end {
+		return None;
+	}
+
+	let phi = x_axis_rotation_degrees.to_radians();
+	let (cos_phi, sin_phi) = (phi.cos(), phi.sin());
+
+	let half_delta = (start - end) / 2.;
+	let x1p = cos_phi * half_delta.x + sin_phi * half_delta.y;
+	let y1p = -sin_phi * half_delta.x + cos_phi * half_delta.y;
+
+	let lambda = (x1p * x1p) / (rx * rx) + (y1p * y1p) / (ry * ry);
+	if lambda > 1. {
+		rx *= lambda.sqrt();
+		ry *= lambda.sqrt();
+	}
+
+	let sign = if large_arc != sweep { 1. } else { -1. };
+	let numerator = (rx * rx * ry * ry - rx * rx * y1p * y1p - ry * ry * x1p * x1p).max(0.);
+	let denominator = rx * rx * y1p * y1p + ry * ry * x1p * x1p;
+	let coefficient = sign * (numerator / denominator).sqrt();
+	let cxp = coefficient * rx * y1p / ry;
+	let cyp = -coefficient * ry * x1p / rx;
+
+	let center = DVec2::new(cos_phi * cxp - sin_phi * cyp, sin_phi * cxp + cos_phi * cyp) + (start + end) / 2.;
+
+	let angle_between = |ux: f64, uy: f64, vx: f64, vy: f64| -> f64 {
+		let dot = ux * vx + uy * vy;
+		let length = ((ux * ux + uy * uy) * (vx * vx + vy * vy)).sqrt();
+		let mut angle = (dot / length).clamp(-1., 1.).acos();
+		if ux * vy - uy * vx < 0. {
+			angle = -angle;
+		}
+		angle
+	};
+
+	let start_angle = angle_between(1., 0., (x1p - cxp) / rx, (y1p - cyp) / ry);
+	let mut sweep_angle = angle_between((x1p - cxp) / rx, (y1p - cyp) / ry, (-x1p - cxp) / rx, (-y1p - cyp) / ry);
+	if !sweep && sweep_angle > 0. {
+		sweep_angle -= TAU;
+	}
+	if sweep && sweep_angle < 0. {
+		sweep_angle += TAU;
+	}
+
+	// Map a point on the unit circle, in the ellipse's local (unrotated, unscaled) space, to the final coordinate space
+	let map = |x: f64, y: f64| -> DVec2 {
+		let scaled = DVec2::new(x * rx, y * ry);
+		DVec2::new(cos_phi * scaled.x - sin_phi * scaled.y, sin_phi * scaled.x + cos_phi * scaled.y) + center
+	};
+
+	let segment_count = (sweep_angle.abs() / (PI / 2.)).ceil().max(1.) as usize;
+	let segment_sweep = sweep_angle / segment_count as f64;
+
+	let mut cubics = Vec::with_capacity(segment_count);
+	let handle_length = cubic_arc_handle_length(segment_sweep);
+	for segment_index in 0..segment_count {
+		let a = start_angle + segment_index as f64 * segment_sweep;
+		let b = a + segment_sweep;
+
+		let p0 = map(a.cos(), a.sin());
+		let p3 = map(b.cos(), b.sin());
+		let tangent0 = map(a.cos() - a.sin() * handle_length, a.sin() + a.cos() * handle_length) - p0;
+		let tangent3 = map(b.cos() + b.sin() * handle_length, b.sin() - b.cos() * handle_length) - p3;
+
+		// The last sub-arc's endpoint is mathematically `end`, but floating-point error in the trig above (e.g. a
+		// full-sweep arc landing at `sin(2π) ≈ -2.449e-15` rather than exactly `0`) can drift it by a tiny amount;
+		// snap it back so repeated round-trips through `to_svg`/`from_svg` don't accumulate that drift.
+		let anchor = if segment_index == segment_count - 1 { end } else { p3 };
+		cubics.push((p0 + tangent0, p3 + tangent3, anchor));
+	}
+
+	Some(cubics)
+}
+
+/// The running state used while parsing a single subpath's worth of path data, tracking enough history to resolve
+/// relative coordinates and the `S`/`T` shorthand commands' reflected control points.
+#[derive(Default)]
+struct SvgParseState {
+	groups: Vec<ManipulatorGroup>,
+	current: DVec2,
+	subpath_start: DVec2,
+	previous_cubic_control: Option<DVec2>,
+	previous_quadratic_control: Option<DVec2>,
+	closed: bool,
+}
+
+impl SvgParseState {
+	fn push_anchor(&mut self, anchor: DVec2, in_handle: Option<DVec2>) {
+		self.groups.push(ManipulatorGroup { anchor, in_handle, out_handle: None });
+		self.current = anchor;
+		self.previous_cubic_control = None;
+		self.previous_quadratic_control = None;
+	}
+
+	fn set_out_handle(&mut self, out_handle: DVec2) {
+		if let Some(last) = self.groups.last_mut() {
+			last.out_handle = Some(out_handle);
+		}
+	}
+
+	fn cubic_to(&mut self, control1: DVec2, control2: DVec2, end: DVec2) {
+		self.set_out_handle(control1);
+		self.push_anchor(end, Some(control2));
+		self.previous_cubic_control = Some(control2);
+	}
+
+	fn quadratic_to(&mut self, control: DVec2, end: DVec2) {
+		let control1 = self.current + (control - self.current) * (2. / 3.);
+		let control2 = end + (control - end) * (2. / 3.);
+		self.set_out_handle(control1);
+		self.push_anchor(end, Some(control2));
+		self.previous_quadratic_control = Some(control);
+	}
+
+	fn arc_to(&mut self, rx: f64, ry: f64, x_axis_rotation: f64, large_arc: bool, sweep: bool, end: DVec2) {
+		match arc_to_cubics(self.current, rx, ry, x_axis_rotation, large_arc, sweep, end) {
+			Some(cubics) => {
+				for (control1, control2, anchor) in cubics {
+					self.cubic_to(control1, control2, anchor);
+				}
+			}
+			None => self.push_anchor(end, None),
+		}
+	}
+}
+
+/// Functionality for converting `Subpath` to and from SVG path (`d` attribute) syntax.
+impl Subpath {
+	/// Parses a single SVG subpath (a `d` attribute containing at most one `M`/`m` command) into a `Subpath`.
+	/// For path data with multiple subpaths, use [`Subpath::from_svg_multiple`] instead.
+	pub fn from_svg(d: &str) -> Subpath {
+		let mut subpaths = Subpath::from_svg_multiple(d);
+		subpaths.pop().unwrap_or_else(|| Subpath::new(vec![], false))
+	}
+
+	/// Parses an SVG `d` attribute containing one or more subpaths (one per `M`/`m` command) into a `Vec<Subpath>`.
+	pub fn from_svg_multiple(d: &str) -> Vec<Subpath> {
+		let mut tokenizer = PathTokenizer::new(d);
+		let mut subpaths = Vec::new();
+		let mut state: Option<SvgParseState> = None;
+		// The command currently in effect. A bare coordinate pair with no command letter repeats this command,
+		// except that repeats of `M`/`m` are implicit `L`/`l` commands, and `Z` never repeats, per the SVG grammar.
+		let mut active_command: Option<char> = None;
+
+		loop {
+			if let Some(letter) = tokenizer.next_command() {
+				active_command = Some(letter);
+			} else if active_command.is_none() {
+				break;
+			}
+			let Some(command) = active_command else { break };
+			let is_relative = command.is_ascii_lowercase();
+
+			if matches!(command, 'M' | 'm') {
+				let previous_current = state.as_ref().map(|previous_state| previous_state.current);
+				if let Some(finished) = state.take() {
+					subpaths.push(Subpath::new(finished.groups, finished.closed));
+				}
+				let Some(mut point) = tokenizer.next_point() else { break };
+				if is_relative {
+					if let Some(previous_current) = previous_current {
+						point += previous_current;
+					}
+				}
+				state = Some(SvgParseState {
+					groups: vec![ManipulatorGroup {
+						anchor: point,
+						in_handle: None,
+						out_handle: None,
+					}],
+					current: point,
+					subpath_start: point,
+					..Default::default()
+				});
+				active_command = Some(if is_relative { 'l' } else { 'L' });
+				continue;
+			}
+
+			let Some(active) = state.as_mut() else { break };
+			let origin = active.current;
+
+			match command {
+				'L' | 'l' => {
+					let Some(mut point) = tokenizer.next_point() else { break };
+					if is_relative {
+						point += origin;
+					}
+					active.push_anchor(point, None);
+				}
+				'H' | 'h' => {
+					let Some(mut x) = tokenizer.next_number() else { break };
+					if is_relative {
+						x += origin.x;
+					}
+					active.push_anchor(DVec2::new(x, origin.y), None);
+				}
+				'V' | 'v' => {
+					let Some(mut y) = tokenizer.next_number() else { break };
+					if is_relative {
+						y += origin.y;
+					}
+					active.push_anchor(DVec2::new(origin.x, y), None);
+				}
+				'C' | 'c' => {
+					let (Some(mut c1), Some(mut c2), Some(mut end)) = (tokenizer.next_point(), tokenizer.next_point(), tokenizer.next_point()) else { break };
+					if is_relative {
+						c1 += origin;
+						c2 += origin;
+						end += origin;
+					}
+					active.cubic_to(c1, c2, end);
+				}
+				'S' | 's' => {
+					let (Some(mut c2), Some(mut end)) = (tokenizer.next_point(), tokenizer.next_point()) else { break };
+					if is_relative {
+						c2 += origin;
+						end += origin;
+					}
+					let c1 = active.previous_cubic_control.map(|reflected| 2. * origin - reflected).unwrap_or(origin);
+					active.cubic_to(c1, c2, end);
+				}
+				'Q' | 'q' => {
+					let (Some(mut control), Some(mut end)) = (tokenizer.next_point(), tokenizer.next_point()) else { break };
+					if is_relative {
+						control += origin;
+						end += origin;
+					}
+					active.quadratic_to(control, end);
+				}
+				'T' | 't' => {
+					let Some(mut end) = tokenizer.next_point() else { break };
+					if is_relative {
+						end += origin;
+					}
+					let control = active.previous_quadratic_control.map(|reflected| 2. * origin - reflected).unwrap_or(origin);
+					active.quadratic_to(control, end);
+				}
+				'A' | 'a' => {
+					let (Some(rx), Some(ry), Some(x_axis_rotation)) = (tokenizer.next_number(), tokenizer.next_number(), tokenizer.next_number()) else { break };
+					let (Some(large_arc), Some(sweep)) = (tokenizer.next_flag(), tokenizer.next_flag()) else { break };
+					let Some(mut end) = tokenizer.next_point() else { break };
+					if is_relative {
+						end += origin;
+					}
+					active.arc_to(rx, ry, x_axis_rotation, large_arc, sweep, end);
+				}
+				'Z' | 'z' => {
+					// An explicit segment (straight or curved) drawn back to `subpath_start` just before `Z` duplicates
+					// the line `Z` already closes with; fold its anchor into group 0 instead of keeping a second vertex
+					if active.groups.len() > 1 && active.groups.last().unwrap().anchor == active.subpath_start {
+						let closing_in_handle = active.groups.pop().unwrap().in_handle;
+						active.groups[0].in_handle = closing_in_handle;
+					}
+					active.closed = true;
+					active.current = active.subpath_start;
+					// `Z` never implicitly repeats, and nothing about it carries into a following bare coordinate pair
+					active_command = None;
+				}
+				_ => break,
+			}
+		}
+
+		if let Some(finished) = state {
+			subpaths.push(Subpath::new(finished.groups, finished.closed));
+		}
+
+		subpaths
+	}
+
+	/// Serializes this `Subpath` to SVG path (`d` attribute) syntax, using `C` for curved segments and `L` for
+	/// straight ones. For a collection of subpaths sharing one `d` attribute, use [`Subpath::to_svg_multiple`].
+	pub fn to_svg(&self) -> String {
+		Subpath::to_svg_multiple(std::slice::from_ref(self))
+	}
+
+	/// Serializes a collection of `Subpath`s into a single SVG `d` attribute, one `M` per subpath.
+	pub fn to_svg_multiple(subpaths: &[Subpath]) -> String {
+		let mut d = String::new();
+
+		for subpath in subpaths {
+			if subpath.manipulator_groups.is_empty() {
+				continue;
+			}
+
+			let first = &subpath.manipulator_groups[0];
+			d.push_str(&format!("M {} {} ", first.anchor.x, first.anchor.y));
+
+			let mut write_segment = |d: &mut String, previous: &ManipulatorGroup, next: &ManipulatorGroup| {
+				match (previous.out_handle, next.in_handle) {
+					(None, None) => d.push_str(&format!("L {} {} ", next.anchor.x, next.anchor.y)),
+					(out_handle, in_handle) => {
+						let control1 = out_handle.unwrap_or(previous.anchor);
+						let control2 = in_handle.unwrap_or(next.anchor);
+						d.push_str(&format!(
+							"C {} {} {} {} {} {} ",
+							control1.x, control1.y, control2.x, control2.y, next.anchor.x, next.anchor.y
+						));
+					}
+				}
+			};
+
+			for pair in subpath.manipulator_groups.windows(2) {
+				write_segment(&mut d, &pair[0], &pair[1]);
+			}
+
+			if subpath.closed {
+				let last = subpath.manipulator_groups.last().unwrap();
+				// `Z` already draws the closing line back to the start, so a straight closing segment needs no
+				// explicit command; only a curved one needs writing out, to carry its control points
+				if last.out_handle.is_some() || first.in_handle.is_some() {
+					write_segment(&mut d, last, first);
+				}
+				d.push_str("Z ");
+			}
+		}
+
+		d.trim_end().to_string()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parses_a_simple_line_path() {
+		let subpath = Subpath::from_svg("M 0 0 L 10 0 L 10 10 Z");
+		assert!(subpath.closed);
+		assert_eq!(subpath.manipulator_groups.len(), 3);
+		assert_eq!(subpath.manipulator_groups[0].anchor, DVec2::new(0., 0.));
+		assert_eq!(subpath.manipulator_groups[1].anchor, DVec2::new(10., 0.));
+		assert_eq!(subpath.manipulator_groups[2].anchor, DVec2::new(10., 10.));
+	}
+
+	#[test]
+	fn parses_relative_commands() {
+		let subpath = Subpath::from_svg("M 0 0 l 10 0 l 0 10");
+		assert_eq!(subpath.manipulator_groups[1].anchor, DVec2::new(10., 0.));
+		assert_eq!(subpath.manipulator_groups[2].anchor, DVec2::new(10., 10.));
+	}
+
+	#[test]
+	fn parses_cubic_curve_commands() {
+		let subpath = Subpath::from_svg("M 0 0 C 0 10 10 10 10 0");
+		assert_eq!(subpath.manipulator_groups[0].out_handle, Some(DVec2::new(0., 10.)));
+		assert_eq!(subpath.manipulator_groups[1].in_handle, Some(DVec2::new(10., 10.)));
+		assert_eq!(subpath.manipulator_groups[1].anchor, DVec2::new(10., 0.));
+	}
+
+	#[test]
+	fn elevates_quadratic_curves_to_cubic_handles() {
+		let subpath = Subpath::from_svg("M 0 0 Q 5 10 10 0");
+		let p0 = DVec2::new(0., 0.);
+		let control = DVec2::new(5., 10.);
+		let p3 = DVec2::new(10., 0.);
+		assert_eq!(subpath.manipulator_groups[0].out_handle, Some(p0 + (control - p0) * (2. / 3.)));
+		assert_eq!(subpath.manipulator_groups[1].in_handle, Some(p3 + (control - p3) * (2. / 3.)));
+	}
+
+	#[test]
+	fn round_trips_a_cubic_path_through_svg() {
+		let d = "M 0 0 C 0 10 10 10 10 0 L 20 0 Z";
+		let subpath = Subpath::from_svg(d);
+		let roundtripped = Subpath::from_svg(&subpath.to_svg());
+
+		assert_eq!(subpath.manipulator_groups, roundtripped.manipulator_groups);
+		assert_eq!(subpath.closed, roundtripped.closed);
+	}
+
+	#[test]
+	fn round_trips_a_curved_closing_segment_without_duplicating_the_start_anchor() {
+		// The path closes with a curve back to its start, not a straight line; the closing `C` must still fold onto
+		// group 0 instead of leaving a spurious extra vertex at the start point
+		let d = "M 0 0 C 0 10 10 10 10 0 C 15 5 5 5 0 0 Z";
+		let subpath = Subpath::from_svg(d);
+		assert_eq!(subpath.manipulator_groups.len(), 2);
+
+		let roundtripped = Subpath::from_svg(&subpath.to_svg());
+		assert_eq!(subpath.manipulator_groups, roundtripped.manipulator_groups);
+		assert_eq!(subpath.closed, roundtripped.closed);
+	}
+
+	#[test]
+	fn parses_multiple_subpaths() {
+		let subpaths = Subpath::from_svg_multiple("M 0 0 L 10 0 M 20 20 L 30 20");
+		assert_eq!(subpaths.len(), 2);
+		assert_eq!(subpaths[0].manipulator_groups[0].anchor, DVec2::new(0., 0.));
+		assert_eq!(subpaths[1].manipulator_groups[0].anchor, DVec2::new(20., 20.));
+	}
+
+	#[test]
+	fn relative_moveto_after_a_subpath_offsets_from_its_last_point() {
+		// The `m 5 5` should offset from `(10, 0)`, the previous subpath's last point, not be treated as absolute
+		let subpaths = Subpath::from_svg_multiple("M 0 0 L 10 0 m 5 5 L 20 10");
+		assert_eq!(subpaths.len(), 2);
+		assert_eq!(subpaths[1].manipulator_groups[0].anchor, DVec2::new(15., 5.));
+	}
+
+	#[test]
+	fn decomposes_an_elliptical_arc_into_cubics() {
+		let subpath = Subpath::from_svg("M 0 0 A 10 10 0 0 1 20 0");
+		// A semicircle is split into at least two ≤90° cubic segments
+		assert!(subpath.manipulator_groups.len() >= 3);
+		// The trig used to decompose the arc accumulates floating-point error (e.g. a full sweep lands at
+		// `sin(2π) ≈ -2.449e-15` rather than exactly `0`), but the last sub-arc's anchor is snapped onto the endpoint
+		let anchor = subpath.manipulator_groups.last().unwrap().anchor;
+		assert!((anchor - DVec2::new(20., 0.)).length() < 1e-9);
+	}
+}
@@ -1,13 +1,164 @@
 use super::*;
 use crate::ComputeType;
+use glam::DVec2;
+use std::f64::consts::{PI, TAU};
+
+// The arc-length error tolerance used for `ComputeType::Euclidean`, which doesn't let the caller specify one directly.
+const DEFAULT_EUCLIDEAN_ERROR: f64 = 1e-3;
+// The default flatness tolerance for `Subpath::offset`, in the same units as the subpath's coordinates.
+const DEFAULT_OFFSET_ERROR: f64 = 0.1;
+// A miter limit for `Join::Miter`: the ratio of miter length to the gap between the offset ends beyond which it falls back to a bevel.
+const DEFAULT_MITER_LIMIT: f64 = 4.;
+
+/// Returns the curve's start anchor, handles (if any), and end anchor, in order, as a single point list.
+/// A linear curve has 2 points, a quadratic has 3, and a cubic has 4.
+fn control_points(curve: Bezier) -> Vec<DVec2> {
+	let mut points = vec![curve.start()];
+	points.extend(curve.handle_start());
+	points.extend(curve.handle_end());
+	points.push(curve.end());
+	points
+}
+
+/// Returns the length of the polygon connecting a curve's start anchor, its handles (if any), and its end anchor.
+/// This is always at least as long as the curve itself, and converges to the curve's actual arc length as the curve is subdivided.
+fn control_polygon_length(curve: Bezier) -> f64 {
+	control_points(curve).windows(2).map(|pair| (pair[1] - pair[0]).length()).sum()
+}
+
+/// Evaluates a Bezier curve given as a raw point list (as returned by `control_points`) at `t` via de Casteljau's algorithm.
+fn evaluate_points(points: &[DVec2], t: f64) -> DVec2 {
+	let mut points = points.to_vec();
+	while points.len() > 1 {
+		points = points.windows(2).map(|pair| pair[0].lerp(pair[1], t)).collect();
+	}
+	points[0]
+}
+
+/// Returns the control points of the derivative of a Bezier curve given as a raw point list, which is itself a Bezier curve one degree lower.
+fn derivative_points(points: &[DVec2]) -> Vec<DVec2> {
+	let degree = (points.len() - 1) as f64;
+	points.windows(2).map(|pair| degree * (pair[1] - pair[0])).collect()
+}
+
+/// Returns the tangent vector (not necessarily normalized, and zero for a zero-length linear curve) of `curve` at `t`.
+fn tangent_at(curve: Bezier, t: f64) -> DVec2 {
+	let points = control_points(curve);
+	if points.len() < 2 {
+		return DVec2::ZERO;
+	}
+	evaluate_points(&derivative_points(&points), t)
+}
+
+/// Rotates a vector by 90 degrees counter-clockwise.
+fn rotate90(vector: DVec2) -> DVec2 {
+	DVec2::new(-vector.y, vector.x)
+}
+
+/// Returns the unit normal of `curve` at `t`, or `DVec2::ZERO` if the tangent there is degenerate.
+fn normal_at(curve: Bezier, t: f64) -> DVec2 {
+	rotate90(tangent_at(curve, t).normalize_or_zero())
+}
+
+/// The handle length, as a fraction of the radius, that approximates a circular arc spanning `angle` radians with a single cubic Bezier.
+/// Accurate for `angle <= FRAC_PI_2`; larger sweeps should be split into multiple arcs before calling this.
+pub(crate) fn cubic_arc_handle_length(angle: f64) -> f64 {
+	(4. / 3.) * (angle / 4.).tan()
+}
+
+/// Approximates a single Bezier segment's arc length by recursively subdividing it (at its midpoint) until the gap between
+/// the chord length and the control polygon length — an upper and lower bound on the true arc length — is within `error`.
+fn segment_arc_length(curve: Bezier, error: f64) -> f64 {
+	let chord_length = (curve.end() - curve.start()).length();
+	let control_polygon_length = control_polygon_length(curve);
+
+	if control_polygon_length - chord_length <= error {
+		return (chord_length + control_polygon_length) / 2.;
+	}
+
+	let [first_half, second_half] = curve.split(0.5);
+	segment_arc_length(first_half, error) + segment_arc_length(second_half, error)
+}
+
+/// Locates the parametric `t`, local to `curve`, at which the accumulated arc length from the curve's start equals `target_distance`.
+/// Works by recursively bisecting the curve at its midpoint and descending into whichever half contains the target distance,
+/// until the remaining piece is no longer than `error`, then interpolating linearly along that piece.
+fn find_local_t_for_distance(curve: Bezier, target_distance: f64, error: f64) -> f64 {
+	let total_length = segment_arc_length(curve, error);
+	find_local_t_for_distance_within(curve, target_distance, total_length, error)
+}
+
+/// Same as [`find_local_t_for_distance`], but takes `curve`'s already-known `total_length` instead of recomputing it.
+/// Threading the length down this way keeps each level of the bisection doing only the work for its own half, rather
+/// than re-deriving the length of the whole remaining curve at every level of recursion.
+fn find_local_t_for_distance_within(curve: Bezier, target_distance: f64, total_length: f64, error: f64) -> f64 {
+	if target_distance <= 0. {
+		return 0.;
+	}
+	if target_distance >= total_length {
+		return 1.;
+	}
+	if total_length <= error {
+		return target_distance / total_length;
+	}
+
+	let [first_half, second_half] = curve.split(0.5);
+	let first_half_length = segment_arc_length(first_half, error);
+
+	if target_distance <= first_half_length {
+		0.5 * find_local_t_for_distance_within(first_half, target_distance, first_half_length, error)
+	} else {
+		let second_half_length = total_length - first_half_length;
+		0.5 + 0.5 * find_local_t_for_distance_within(second_half, target_distance - first_half_length, second_half_length, error)
+	}
+}
 
 /// Functionality that transforms Subpaths, such as split, reduce, offset, etc.
 impl Subpath {
+	/// Converts a Euclidean `t` (a fraction of the Subpath's total arc length) into the equivalent parametric `t`,
+	/// by locating which segment the target distance falls in and then inverting arc length within that segment.
+	fn euclidean_to_parametric(&self, t: f64, error: f64) -> f64 {
+		assert!((0.0..=1.).contains(&t));
+		// Guard the endpoints explicitly: `total_length` (an iterator `.sum()`) and the final segment's
+		// `target_distance` (reached via sequential `-=`) can differ by up to an ULP, so without this the
+		// bisection below could return a `t` a hair under `1.` instead of exactly `1.`
+		if t == 0. {
+			return 0.;
+		}
+		if t == 1. {
+			return 1.;
+		}
+
+		let segment_lengths: Vec<f64> = self.iter().map(|curve| segment_arc_length(curve, error)).collect();
+		let total_length: f64 = segment_lengths.iter().sum();
+		let mut target_distance = t * total_length;
+
+		let number_of_curves = self.len_segments();
+		for (segment_index, &segment_length) in segment_lengths.iter().enumerate() {
+			if target_distance <= segment_length || segment_index == number_of_curves - 1 {
+				let curve = self.iter().nth(segment_index).unwrap();
+				let local_t = find_local_t_for_distance(curve, target_distance, error);
+				return (segment_index as f64 + local_t) / number_of_curves as f64;
+			}
+			target_distance -= segment_length;
+		}
+
+		unreachable!("the final segment is always accepted by the `segment_index == number_of_curves - 1` fallback above")
+	}
+
 	/// Returns either one or two Subpaths that result from splitting the original Subpath at the point corresponding to `t`.
 	/// If the original Subpath was closed, a single open Subpath will be returned.
 	/// If the original Subpath was open, two open Subpaths will be returned.
 	pub fn split(&self, t: ComputeType) -> (Subpath, Option<Subpath>) {
 		match t {
+			ComputeType::Euclidean(t) => {
+				let parametric_t = self.euclidean_to_parametric(t, DEFAULT_EUCLIDEAN_ERROR);
+				self.split(ComputeType::Parametric(parametric_t))
+			}
+			ComputeType::EuclideanWithinError { t, epsilon } => {
+				let parametric_t = self.euclidean_to_parametric(t, epsilon);
+				self.split(ComputeType::Parametric(parametric_t))
+			}
 			ComputeType::Parametric(t) => {
 				assert!((0.0..=1.).contains(&t));
 
@@ -82,13 +233,377 @@ impl Subpath {
 					(Subpath::new(first_split, false), Some(Subpath::new(second_split, false)))
 				}
 			}
-			// TODO: change this implementation to Euclidean compute
-			ComputeType::Euclidean(_t) => todo!(),
-			ComputeType::EuclideanWithinError { t: _, epsilon: _ } => todo!(),
 		}
 	}
 }
 
+/// The corner style used to connect adjacent offset segments in `Subpath::offset` and `Subpath::outline`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Join {
+	/// Extends both segments' tangents until they meet, falling back to `Bevel` once the miter length exceeds this many times the gap it bridges.
+	Miter(f64),
+	/// Connects the segments with a circular arc.
+	Round,
+	/// Connects the segments with a straight line.
+	Bevel,
+}
+
+/// The end style used to close off the two ends of the fill region produced by `Subpath::outline`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Cap {
+	/// The two offset sides are joined directly, ending flush with the path's endpoint.
+	Butt,
+	/// The two offset sides are joined by a semicircular arc.
+	Round,
+	/// The two offset sides are extended by the stroke's half-width before being joined, like `Butt` but squared off further out.
+	Square,
+}
+
+/// Approximates the offset (by `distance`, along the curve's normal) of a single Bezier segment, recursively subdividing
+/// it at its midpoint until the offset is flat enough to approximate with a single same-degree Bezier within `tolerance`.
+fn offset_curve(curve: Bezier, distance: f64, tolerance: f64) -> Vec<Bezier> {
+	if distance == 0. {
+		return vec![curve];
+	}
+
+	let tangent_start = tangent_at(curve, 0.);
+	let tangent_end = tangent_at(curve, 1.);
+	let flatness_error = if tangent_start == DVec2::ZERO || tangent_end == DVec2::ZERO {
+		0.
+	} else {
+		tangent_start.angle_between(tangent_end).abs() * distance.abs()
+	};
+
+	if flatness_error <= tolerance {
+		return vec![offset_curve_once(curve, distance)];
+	}
+
+	let [first_half, second_half] = curve.split(0.5);
+	let mut result = offset_curve(first_half, distance, tolerance);
+	result.extend(offset_curve(second_half, distance, tolerance));
+	result
+}
+
+/// Offsets a single Bezier segment by shifting each of its control points along the normal sampled at that point's parameter.
+/// This is only accurate once the segment's tangent turns through a small angle, which is why `offset_curve` subdivides until it does.
+fn offset_curve_once(curve: Bezier, distance: f64) -> Bezier {
+	let points = control_points(curve);
+	let last_index = points.len() - 1;
+
+	let shifted: Vec<DVec2> = points
+		.iter()
+		.enumerate()
+		.map(|(index, &point)| {
+			let t = index as f64 / last_index as f64;
+			point + distance * normal_at(curve, t)
+		})
+		.collect();
+
+	match shifted.len() {
+		2 => Bezier::from_linear_dvec2(shifted[0], shifted[1]),
+		3 => Bezier::from_quadratic_dvec2(shifted[0], shifted[1], shifted[2]),
+		4 => Bezier::from_cubic_dvec2(shifted[0], shifted[1], shifted[2], shifted[3]),
+		_ => unreachable!("a Bezier curve only ever has 2, 3, or 4 control points"),
+	}
+}
+
+/// Finds where the lines through `p0` (direction `dir0`) and `p1` (direction `dir1`) intersect, or `None` if they're parallel.
+fn line_intersection(p0: DVec2, dir0: DVec2, p1: DVec2, dir1: DVec2) -> Option<DVec2> {
+	let denominator = dir0.x * dir1.y - dir0.y * dir1.x;
+	if denominator.abs() < 1e-9 {
+		return None;
+	}
+	let diff = p1 - p0;
+	let s = (diff.x * dir1.y - diff.y * dir1.x) / denominator;
+	Some(p0 + s * dir0)
+}
+
+/// Approximates, as a single cubic Bezier's handles, the circular arc around `pivot` from `start` to `end`.
+/// Returns `(out_handle, in_handle)`: the handle to attach after `start` and the handle to attach before `end`.
+fn round_arc_handles(pivot: DVec2, start: DVec2, end: DVec2) -> Option<(DVec2, DVec2)> {
+	let start_vec = start - pivot;
+	let end_vec = end - pivot;
+	let radius = start_vec.length();
+	if radius <= 0. {
+		return None;
+	}
+
+	let mut sweep = end_vec.y.atan2(end_vec.x) - start_vec.y.atan2(start_vec.x);
+	while sweep <= -PI {
+		sweep += TAU;
+	}
+	while sweep > PI {
+		sweep -= TAU;
+	}
+
+	let handle_length = radius * cubic_arc_handle_length(sweep);
+	let out_tangent = rotate90(start_vec.normalize_or_zero());
+	let in_tangent = rotate90(end_vec.normalize_or_zero());
+	Some((start + handle_length * out_tangent, end - handle_length * in_tangent))
+}
+
+/// Appends one chain of touching Bezier segments (as returned by `offset_curve`) as manipulator groups, overriding the
+/// very first group's `in_handle` with `first_in_handle` when a join has already computed one (e.g. for `Join::Round`).
+fn append_curve_chain(groups: &mut Vec<ManipulatorGroup>, chain: &[Bezier], first_in_handle: Option<DVec2>) {
+	for (index, piece) in chain.iter().enumerate() {
+		let in_handle = if index == 0 { first_in_handle } else { chain[index - 1].handle_end() };
+		groups.push(ManipulatorGroup {
+			anchor: piece.start(),
+			in_handle,
+			out_handle: piece.handle_start(),
+		});
+	}
+
+	let last_piece = chain.last().unwrap();
+	groups.push(ManipulatorGroup {
+		anchor: last_piece.end(),
+		in_handle: last_piece.handle_end(),
+		out_handle: None,
+	});
+}
+
+/// Connects the end of one offset chain (`previous_end`, the last segment of the curve before the corner) to the start
+/// of the next (`next_start`) around the original corner at `pivot`. `Join::Round` sets `groups`' last entry's `out_handle`
+/// in place, and `Join::Miter` pushes a corner group onto `groups` when within its limit.
+/// Returns the `in_handle` the caller should use for the first group of `next_start`'s chain, if any.
+fn join_chains(groups: &mut Vec<ManipulatorGroup>, pivot: DVec2, previous_end: Bezier, next_start: Bezier, join: Join) -> Option<DVec2> {
+	let p0 = previous_end.end();
+	let p1 = next_start.start();
+
+	match join {
+		Join::Bevel => None,
+		Join::Miter(limit) => {
+			let t0 = tangent_at(previous_end, 1.).normalize_or_zero();
+			let t1 = tangent_at(next_start, 0.).normalize_or_zero();
+			if let Some(intersection) = line_intersection(p0, t0, p1, t1) {
+				let gap = (p1 - p0).length();
+				let miter_length = (intersection - p0).length().max((intersection - p1).length());
+				if gap > 0. && miter_length / gap <= limit {
+					groups.push(ManipulatorGroup {
+						anchor: intersection,
+						in_handle: None,
+						out_handle: None,
+					});
+				}
+			}
+			None
+		}
+		Join::Round => {
+			let arc = round_arc_handles(pivot, p0, p1)?;
+			if let Some(previous_group) = groups.last_mut() {
+				previous_group.out_handle = Some(arc.0);
+			}
+			Some(arc.1)
+		}
+	}
+}
+
+/// Closes off one end of an outline between the last-pushed group (at `from`) and the not-yet-pushed group at `to`,
+/// around the original path's endpoint at `pivot`. `outward` is the original path's tangent direction at that
+/// endpoint, pointing away from the path (i.e. the direction `Cap::Square` should extrude past the endpoint).
+/// Returns the `in_handle` the caller should use for the group at `to`.
+fn apply_cap(groups: &mut Vec<ManipulatorGroup>, pivot: DVec2, from: DVec2, to: DVec2, outward: DVec2, cap: Cap) -> Option<DVec2> {
+	match cap {
+		Cap::Butt => None,
+		Cap::Round => {
+			let arc = round_arc_handles(pivot, from, to)?;
+			if let Some(previous_group) = groups.last_mut() {
+				previous_group.out_handle = Some(arc.0);
+			}
+			Some(arc.1)
+		}
+		Cap::Square => {
+			let half_width = (from - pivot).length();
+			groups.push(ManipulatorGroup {
+				anchor: from + outward * half_width,
+				in_handle: None,
+				out_handle: None,
+			});
+			groups.push(ManipulatorGroup {
+				anchor: to + outward * half_width,
+				in_handle: None,
+				out_handle: None,
+			});
+			None
+		}
+	}
+}
+
+/// Functionality for converting a Subpath into a parallel offset curve, or a stroked outline ready to be filled.
+impl Subpath {
+	/// Returns a Subpath that runs parallel to `self`, offset by the signed `distance` along the curve's normal
+	/// (positive offsets to the left of the direction of travel), with corners connected according to `join`.
+	pub fn offset(&self, distance: f64, join: Join) -> Subpath {
+		let segments: Vec<Bezier> = self.iter().collect();
+		if segments.is_empty() {
+			return Subpath::new(vec![], self.closed);
+		}
+
+		let chains: Vec<Vec<Bezier>> = segments.into_iter().map(|curve| offset_curve(curve, distance, DEFAULT_OFFSET_ERROR)).collect();
+
+		let mut manipulator_groups: Vec<ManipulatorGroup> = Vec::new();
+		for (index, chain) in chains.iter().enumerate() {
+			let forced_in_handle = if index > 0 {
+				let pivot = self.manipulator_groups[index].anchor;
+				let previous_end = *chains[index - 1].last().unwrap();
+				let next_start = *chain.first().unwrap();
+				join_chains(&mut manipulator_groups, pivot, previous_end, next_start, join)
+			} else {
+				None
+			};
+			append_curve_chain(&mut manipulator_groups, chain, forced_in_handle);
+		}
+
+		if self.closed {
+			let pivot = self.manipulator_groups[0].anchor;
+			let previous_end = *chains.last().unwrap().last().unwrap();
+			let next_start = *chains[0].first().unwrap();
+			let forced_in_handle = join_chains(&mut manipulator_groups, pivot, previous_end, next_start, join);
+			if let Some(in_handle) = forced_in_handle {
+				manipulator_groups[0].in_handle = Some(in_handle);
+			}
+		}
+
+		Subpath::new(manipulator_groups, self.closed)
+	}
+
+	/// Returns the closed fill region of `self` stroked with the given `width`, ready to be rendered by any filling
+	/// rasterizer. Offsets by `±width/2` to get the two sides of the stroke, then joins them with `cap` at the ends.
+	/// Closed input Subpaths don't have a single fill boundary (the outline of a stroked loop is itself two loops,
+	/// an outer boundary and an inner hole), so for those only the outer boundary is returned.
+	pub fn outline(&self, width: f64, join: Join, cap: Cap) -> Subpath {
+		let half_width = width / 2.;
+		let outer = self.offset(half_width, join);
+		let mut inner = self.offset(-half_width, join);
+
+		if self.closed {
+			// Which side is outside depends on the loop's winding, so return whichever side encloses the larger area
+			let enclosed_area = |subpath: &Subpath| {
+				let groups = &subpath.manipulator_groups;
+				let twice_signed_area: f64 = (0..groups.len()).map(|i| groups[i].anchor.perp_dot(groups[(i + 1) % groups.len()].anchor)).sum();
+				twice_signed_area.abs()
+			};
+			return if enclosed_area(&inner) > enclosed_area(&outer) { inner } else { outer };
+		}
+		if outer.manipulator_groups.is_empty() || inner.manipulator_groups.is_empty() {
+			return outer;
+		}
+
+		// Reverse the inner side so both boundaries wind the same way around the filled stroke region
+		inner.manipulator_groups.reverse();
+		for group in inner.manipulator_groups.iter_mut() {
+			std::mem::swap(&mut group.in_handle, &mut group.out_handle);
+		}
+
+		let mut manipulator_groups = outer.manipulator_groups;
+
+		// `Cap::Square` extrudes past the endpoint along the original path's tangent there, not around it
+		let end_outward = tangent_at(self.iter().last().unwrap(), 1.).normalize_or_zero();
+		let start_outward = -tangent_at(self.iter().next().unwrap(), 0.).normalize_or_zero();
+
+		let end_pivot = self.manipulator_groups.last().unwrap().anchor;
+		let end_from = manipulator_groups.last().unwrap().anchor;
+		let end_to = inner.manipulator_groups[0].anchor;
+		if let Some(in_handle) = apply_cap(&mut manipulator_groups, end_pivot, end_from, end_to, end_outward, cap) {
+			inner.manipulator_groups[0].in_handle = Some(in_handle);
+		}
+		manipulator_groups.extend(inner.manipulator_groups);
+
+		let start_pivot = self.manipulator_groups.first().unwrap().anchor;
+		let start_from = manipulator_groups.last().unwrap().anchor;
+		let start_to = manipulator_groups[0].anchor;
+		if let Some(in_handle) = apply_cap(&mut manipulator_groups, start_pivot, start_from, start_to, start_outward, cap) {
+			manipulator_groups[0].in_handle = Some(in_handle);
+		}
+
+		Subpath::new(manipulator_groups, true)
+	}
+}
+
+/// An upper bound on the deviation between a cubic curve `[p0, p1, p2, p3]` and the single quadratic that would replace it
+/// (with control point `(3·p1 − p0 + 3·p2 − p3) / 4`), per the standard bound used by font and tessellation tooling.
+fn cubic_to_single_quadratic_error(points: &[DVec2]) -> f64 {
+	if points.len() < 4 {
+		return 0.;
+	}
+	let deviation = points[0] - 3. * points[1] + 3. * points[2] - points[3];
+	(3f64.sqrt() / 18.) * deviation.length()
+}
+
+/// Returns the control point of the single quadratic curve that most closely approximates cubic curve `points`.
+fn single_quadratic_control(points: &[DVec2]) -> DVec2 {
+	(3. * points[1] - points[0] + 3. * points[2] - points[3]) / 4.
+}
+
+/// Approximates `curve` with one or more quadratic Beziers (returned as degenerate cubics, with handles placed via
+/// the `2/3` elevation rule, to keep a single internal representation), recursively subdividing at the midpoint
+/// until each piece's single-quadratic error is within `tolerance`.
+fn to_quadratic_curve(curve: Bezier, tolerance: f64) -> Vec<Bezier> {
+	let points = control_points(curve);
+
+	// Already degree ≤ 2, so it's already exactly representable as a quadratic
+	if points.len() <= 3 {
+		return vec![curve];
+	}
+
+	if cubic_to_single_quadratic_error(&points) <= tolerance {
+		let control = single_quadratic_control(&points);
+		let p0 = points[0];
+		let p3 = points[3];
+		let c1 = p0 + (control - p0) * (2. / 3.);
+		let c2 = p3 + (control - p3) * (2. / 3.);
+		return vec![Bezier::from_cubic_dvec2(p0, c1, c2, p3)];
+	}
+
+	let [first_half, second_half] = curve.split(0.5);
+	let mut result = to_quadratic_curve(first_half, tolerance);
+	result.extend(to_quadratic_curve(second_half, tolerance));
+	result
+}
+
+/// Functionality for converting a Subpath to use only quadratic (or lower-degree) Bezier segments.
+impl Subpath {
+	/// Returns a Subpath approximating `self` where every segment is a quadratic (or lower-degree) Bezier, within
+	/// `tolerance` of the original. Many rasterizers, GPU tessellators, and font formats (such as TrueType) only
+	/// support quadratics, so this lets a Subpath built from cubics be exported to those pipelines.
+	///
+	/// Quadratics are represented internally as degenerate cubics (via the `2/3` elevation rule), so the returned
+	/// Subpath still reports cubic handles; the quadratic nature is only in how closely those handles were fit.
+	pub fn to_quadratics(&self, tolerance: f64) -> Subpath {
+		let segments: Vec<Bezier> = self.iter().collect();
+		if segments.is_empty() {
+			return Subpath::new(vec![], self.closed);
+		}
+
+		let pieces: Vec<Bezier> = segments.into_iter().flat_map(|curve| to_quadratic_curve(curve, tolerance)).collect();
+
+		let mut manipulator_groups = Vec::with_capacity(pieces.len() + 1);
+		for (index, piece) in pieces.iter().enumerate() {
+			let in_handle = if index == 0 { None } else { pieces[index - 1].handle_end() };
+			manipulator_groups.push(ManipulatorGroup {
+				anchor: piece.start(),
+				in_handle,
+				out_handle: piece.handle_start(),
+			});
+		}
+
+		if self.closed {
+			// The segment from the last piece back to the first anchor is represented implicitly by `closed`,
+			// the same way every other closed Subpath in this crate omits a redundant final manipulator group.
+			manipulator_groups[0].in_handle = pieces.last().unwrap().handle_end();
+		} else {
+			let last_piece = pieces.last().unwrap();
+			manipulator_groups.push(ManipulatorGroup {
+				anchor: last_piece.end(),
+				in_handle: last_piece.handle_end(),
+				out_handle: None,
+			});
+		}
+
+		Subpath::new(manipulator_groups, self.closed)
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -207,6 +722,46 @@ mod tests {
 		assert_eq!(split_pair[1], first.iter().next().unwrap());
 	}
 
+	#[test]
+	fn split_an_open_subpath_euclidean() {
+		let subpath = set_up_open_subpath();
+		let arc_length = |path: &Subpath| path.iter().map(|curve| segment_arc_length(curve, DEFAULT_EUCLIDEAN_ERROR)).sum::<f64>();
+		let total_length = arc_length(&subpath);
+
+		let (first, second) = subpath.split(ComputeType::Euclidean(0.4));
+		let second = second.unwrap();
+
+		// The two pieces should partition the original arc length, each a fraction away from the requested split point
+		assert!((arc_length(&first) - 0.4 * total_length).abs() < 1e-2);
+		assert!((arc_length(&second) - 0.6 * total_length).abs() < 1e-2);
+	}
+
+	#[test]
+	fn split_euclidean_matches_parametric_at_endpoints() {
+		let subpath = set_up_open_subpath();
+
+		let (first, second) = subpath.split(ComputeType::Euclidean(0.));
+		let (first_parametric, second_parametric) = subpath.split(ComputeType::Parametric(0.));
+		assert_eq!(first.manipulator_groups, first_parametric.manipulator_groups);
+		assert_eq!(second.map(|s| s.manipulator_groups), second_parametric.map(|s| s.manipulator_groups));
+
+		let (first, second) = subpath.split(ComputeType::Euclidean(1.));
+		let (first_parametric, second_parametric) = subpath.split(ComputeType::Parametric(1.));
+		assert_eq!(first.manipulator_groups, first_parametric.manipulator_groups);
+		assert_eq!(second.map(|s| s.manipulator_groups), second_parametric.map(|s| s.manipulator_groups));
+	}
+
+	#[test]
+	fn split_euclidean_within_error() {
+		let subpath = set_up_open_subpath();
+		let arc_length = |path: &Subpath| path.iter().map(|curve| segment_arc_length(curve, 1e-6)).sum::<f64>();
+		let total_length = arc_length(&subpath);
+
+		let (first, _) = subpath.split(ComputeType::EuclideanWithinError { t: 0.25, epsilon: 1e-6 });
+
+		assert!((arc_length(&first) - 0.25 * total_length).abs() < 1e-4);
+	}
+
 	#[test]
 	fn split_at_start_of_a_closed_subpath() {
 		let subpath = set_up_closed_subpath();
@@ -234,4 +789,128 @@ mod tests {
 		assert_eq!(first.iter().last().unwrap(), subpath.iter().last().unwrap());
 		assert_eq!(first.iter().next().unwrap(), subpath.iter().next().unwrap());
 	}
+
+	#[test]
+	fn offset_an_open_subpath_preserves_endpoint_count() {
+		let subpath = set_up_open_subpath();
+		let offset = subpath.offset(5., Join::Bevel);
+
+		assert!(!offset.closed);
+		assert_eq!(offset.manipulator_groups.first().unwrap().in_handle, None);
+		assert_eq!(offset.manipulator_groups.last().unwrap().out_handle, None);
+	}
+
+	#[test]
+	fn offset_moves_points_away_by_roughly_the_given_distance() {
+		let subpath = set_up_open_subpath();
+		let distance = 5.;
+		let offset = subpath.offset(distance, Join::Round);
+
+		let original_start = subpath.manipulator_groups[0].anchor;
+		let offset_start = offset.manipulator_groups[0].anchor;
+		assert!(((offset_start - original_start).length() - distance.abs()).abs() < 1.);
+	}
+
+	#[test]
+	fn offset_with_miter_join_falls_back_to_bevel_within_limit() {
+		let subpath = set_up_open_subpath();
+		// Both corners of this path turn by more than 165 degrees, so their miters exceed even the larger limit and
+		// both limits fall back to the same output as a bevel
+		let tight = subpath.offset(5., Join::Miter(1.));
+		let generous = subpath.offset(5., Join::Miter(DEFAULT_MITER_LIMIT));
+		let bevel = subpath.offset(5., Join::Bevel);
+
+		assert_eq!(tight.manipulator_groups.first().unwrap().anchor, generous.manipulator_groups.first().unwrap().anchor);
+		assert_eq!(tight.manipulator_groups, bevel.manipulator_groups);
+		assert_eq!(generous.manipulator_groups, bevel.manipulator_groups);
+	}
+
+	#[test]
+	fn outline_of_an_open_subpath_is_closed() {
+		let subpath = set_up_open_subpath();
+		let outline = subpath.outline(10., Join::Round, Cap::Round);
+
+		assert!(outline.closed);
+		// Both offset sides (forward and reversed) should be present, roughly doubling the manipulator group count
+		assert!(outline.manipulator_groups.len() >= subpath.manipulator_groups.len() * 2);
+	}
+
+	#[test]
+	fn outline_with_square_cap_extends_beyond_endpoints() {
+		let subpath = set_up_open_subpath();
+		let width = 10.;
+		let outline = subpath.outline(width, Join::Round, Cap::Square);
+
+		let start = subpath.manipulator_groups.first().unwrap().anchor;
+		let end = subpath.manipulator_groups.last().unwrap().anchor;
+		let start_outward = -tangent_at(subpath.iter().next().unwrap(), 0.).normalize_or_zero();
+		let end_outward = tangent_at(subpath.iter().last().unwrap(), 1.).normalize_or_zero();
+
+		// Every square-cap anchor should project further along its endpoint's outward tangent than the endpoint
+		// itself, rather than back into the stroke
+		let projects_beyond = |anchor: DVec2, pivot: DVec2, outward: DVec2| (anchor - pivot).dot(outward) > 0.;
+		// The end cap's two anchors directly follow the outer side, and the start cap's two anchors close the outline
+		let groups = &outline.manipulator_groups;
+		let outer_side_len = subpath.offset(width / 2., Join::Round).manipulator_groups.len();
+		let end_cap = [groups[outer_side_len].anchor, groups[outer_side_len + 1].anchor];
+		let start_cap = [groups[groups.len() - 2].anchor, groups[groups.len() - 1].anchor];
+		assert!(end_cap.iter().all(|&anchor| projects_beyond(anchor, end, end_outward)));
+		assert!(start_cap.iter().all(|&anchor| projects_beyond(anchor, start, start_outward)));
+	}
+
+	/// Samples both Subpaths at the same parametric `t` values and returns the largest distance between them.
+	fn max_deviation(original: &Subpath, approximation: &Subpath, samples: usize) -> f64 {
+		(0..=samples)
+			.map(|i| i as f64 / samples as f64)
+			.map(|t| (original.evaluate(ComputeType::Parametric(t)) - approximation.evaluate(ComputeType::Parametric(t))).length())
+			.fold(0., f64::max)
+	}
+
+	/// A single cubic segment, which `to_quadratics` must approximate (the open and closed fixtures hold only quadratic and linear segments).
+	fn set_up_cubic_subpath() -> Subpath {
+		Subpath::new(
+			vec![
+				ManipulatorGroup {
+					anchor: DVec2::new(0., 0.),
+					in_handle: None,
+					out_handle: Some(DVec2::new(0., 100.)),
+				},
+				ManipulatorGroup {
+					anchor: DVec2::new(100., 0.),
+					in_handle: Some(DVec2::new(100., 100.)),
+					out_handle: None,
+				},
+			],
+			false,
+		)
+	}
+
+	#[test]
+	fn to_quadratics_stays_within_tolerance() {
+		let subpath = set_up_cubic_subpath();
+		let tolerance = 0.5;
+		let approximation = subpath.to_quadratics(tolerance);
+
+		// Halving a cubic divides its error bound by 8, so a single cubic is split into equal parametric pieces and
+		// sampling both Subpaths at the same parametric `t` compares matching points
+		assert!(max_deviation(&subpath, &approximation, 50) < tolerance);
+	}
+
+	#[test]
+	fn to_quadratics_on_a_closed_subpath_has_no_extra_group() {
+		let subpath = set_up_closed_subpath();
+		let approximation = subpath.to_quadratics(0.5);
+
+		assert!(approximation.closed);
+		assert_eq!(approximation.manipulator_groups.len(), approximation.iter().count());
+	}
+
+	#[test]
+	fn to_quadratics_with_tight_tolerance_subdivides_more() {
+		let subpath = set_up_cubic_subpath();
+		let loose = subpath.to_quadratics(10.);
+		let tight = subpath.to_quadratics(1e-6);
+
+		assert!(tight.manipulator_groups.len() > loose.manipulator_groups.len());
+	}
 }